@@ -0,0 +1,117 @@
+use crate::{route::Router, Location};
+use alloc::vec;
+use alloc::vec::Vec;
+use ordered_float::OrderedFloat;
+
+/// Finds the optimal visiting order of `stops` between `start` and `end` with the
+/// Held-Karp dynamic programming algorithm.
+///
+/// This runs in `O(2^n * n^2)` time and `O(2^n * n)` space, so `stops` should stay
+/// small (roughly 15 or fewer).
+pub fn solve<R: Router>(
+    router: &R,
+    start: Location,
+    end: Location,
+    stops: &[Location],
+) -> Vec<usize> {
+    let len = stops.len();
+
+    if len == 0 {
+        return vec![];
+    }
+
+    let mut cost = vec![vec![f64::INFINITY; len]; 1 << len];
+    let mut parent = vec![vec![None; len]; 1 << len];
+
+    for (index, &stop) in stops.iter().enumerate() {
+        cost[1 << index][index] = router.route(start, stop);
+    }
+
+    for mask in 1..1 << len {
+        for last in 0..len {
+            if mask & (1 << last) == 0 || !cost[mask][last].is_finite() {
+                continue;
+            }
+
+            for next in 0..len {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+
+                let next_mask = mask | (1 << next);
+                let next_cost = cost[mask][last] + router.route(stops[last], stops[next]);
+
+                if next_cost < cost[next_mask][next] {
+                    cost[next_mask][next] = next_cost;
+                    parent[next_mask][next] = Some(last);
+                }
+            }
+        }
+    }
+
+    let full_mask = (1 << len) - 1;
+    let last = (0..len)
+        .min_by_key(|&index| OrderedFloat(cost[full_mask][index] + router.route(stops[index], end)))
+        .expect("at least one stop");
+
+    let mut order = vec![];
+    let mut mask = full_mask;
+    let mut index = last;
+
+    loop {
+        order.push(index);
+
+        match parent[mask][index] {
+            Some(previous) => {
+                mask &= !(1 << index);
+                index = previous;
+            }
+            None => break,
+        }
+    }
+
+    order.reverse();
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::CrowRouter;
+
+    static ROUTER: CrowRouter = CrowRouter::new();
+
+    #[test]
+    fn solve_no_stops() {
+        let order = solve(&ROUTER, Location::new(0.0, 0.0), Location::new(1.0, 0.0), &[]);
+
+        assert_eq!(order, Vec::<usize>::new());
+    }
+
+    #[test]
+    fn solve_one_stop() {
+        let order = solve(
+            &ROUTER,
+            Location::new(0.0, 0.0),
+            Location::new(1.0, 0.0),
+            &[Location::new(0.5, 0.0)],
+        );
+
+        assert_eq!(order, vec![0]);
+    }
+
+    #[test]
+    fn solve_orders_stops_along_a_line() {
+        let order = solve(
+            &ROUTER,
+            Location::new(0.0, 0.0),
+            Location::new(3.0, 0.0),
+            &[
+                Location::new(2.0, 0.0),
+                Location::new(1.0, 0.0),
+            ],
+        );
+
+        assert_eq!(order, vec![1, 0]);
+    }
+}
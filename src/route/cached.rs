@@ -0,0 +1,90 @@
+use super::Router;
+use crate::{hash_map::HashMap, problem::BaseProblem, Location};
+use alloc::vec::Vec;
+use ordered_float::OrderedFloat;
+
+pub struct MatrixRouter {
+    indexes: HashMap<(OrderedFloat<f64>, OrderedFloat<f64>), usize>,
+    distances: Vec<Vec<f64>>,
+}
+
+impl MatrixRouter {
+    pub fn new<R: Router>(router: &R, problem: &impl BaseProblem) -> Self {
+        let locations = (0..problem.stop_count())
+            .map(|index| problem.stop_location(index))
+            .chain((0..problem.vehicle_count()).flat_map(|index| {
+                [
+                    problem.vehicle_start_location(index),
+                    problem.vehicle_end_location(index),
+                ]
+            }))
+            .collect::<Vec<_>>();
+
+        let mut indexes = HashMap::default();
+
+        for (index, location) in locations.iter().enumerate() {
+            indexes.insert((OrderedFloat(location.x()), OrderedFloat(location.y())), index);
+        }
+
+        let distances = locations
+            .iter()
+            .map(|&from| locations.iter().map(|&to| router.route(from, to)).collect())
+            .collect();
+
+        Self { indexes, distances }
+    }
+
+    fn index(&self, location: Location) -> Option<usize> {
+        self.indexes
+            .get(&(OrderedFloat(location.x()), OrderedFloat(location.y())))
+            .copied()
+    }
+}
+
+impl Router for MatrixRouter {
+    fn route(&self, from: Location, to: Location) -> f64 {
+        match (self.index(from), self.index(to)) {
+            (Some(from), Some(to)) => self.distances[from][to],
+            _ => f64::INFINITY,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{route::CrowRouter, SimpleProblem, Stop, Vehicle};
+
+    static ROUTER: CrowRouter = CrowRouter::new();
+
+    #[test]
+    fn matches_uncached_router_for_known_locations() {
+        let problem = SimpleProblem::new(
+            vec![Vehicle::new()],
+            vec![
+                Stop::new(Location::new(0.0, 0.0)),
+                Stop::new(Location::new(3.0, 4.0)),
+            ],
+        );
+        let matrix_router = MatrixRouter::new(&ROUTER, &problem);
+
+        assert_eq!(
+            matrix_router.route(Location::new(0.0, 0.0), Location::new(3.0, 4.0)),
+            ROUTER.route(Location::new(0.0, 0.0), Location::new(3.0, 4.0)),
+        );
+    }
+
+    #[test]
+    fn degrades_to_infinity_for_unknown_location() {
+        let problem = SimpleProblem::new(
+            vec![Vehicle::new()],
+            vec![Stop::new(Location::new(0.0, 0.0))],
+        );
+        let matrix_router = MatrixRouter::new(&ROUTER, &problem);
+
+        assert_eq!(
+            matrix_router.route(Location::new(0.0, 0.0), Location::new(42.0, 42.0)),
+            f64::INFINITY,
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use super::{CrowRouter, Router};
+use crate::{hash_map::HashMap, Location};
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Reverse;
+use ordered_float::OrderedFloat;
+
+pub struct GraphRouter {
+    crow_router: CrowRouter,
+    nodes: Vec<Location>,
+    indexes: HashMap<(OrderedFloat<f64>, OrderedFloat<f64>), usize>,
+    edges: Vec<Vec<(usize, f64)>>,
+    greedy_weight: f64,
+}
+
+impl GraphRouter {
+    pub fn new(nodes: Vec<Location>, edges: Vec<Vec<(usize, f64)>>, greedy_weight: f64) -> Self {
+        let mut indexes = HashMap::default();
+
+        for (index, node) in nodes.iter().enumerate() {
+            indexes.insert((OrderedFloat(node.x()), OrderedFloat(node.y())), index);
+        }
+
+        Self {
+            crow_router: CrowRouter::new(),
+            nodes,
+            indexes,
+            edges,
+            greedy_weight,
+        }
+    }
+
+    fn index(&self, location: Location) -> Option<usize> {
+        self.indexes
+            .get(&(OrderedFloat(location.x()), OrderedFloat(location.y())))
+            .copied()
+    }
+
+    fn search(&self, start: usize, goal: usize) -> f64 {
+        let mut costs = vec![f64::INFINITY; self.nodes.len()];
+        let mut open_set = BinaryHeap::new();
+
+        costs[start] = 0.0;
+        open_set.push(Reverse((
+            OrderedFloat(
+                self.greedy_weight * self.crow_router.route(self.nodes[start], self.nodes[goal]),
+            ),
+            start,
+        )));
+
+        while let Some(Reverse((_, node))) = open_set.pop() {
+            if node == goal {
+                return costs[goal];
+            }
+
+            for &(next, weight) in &self.edges[node] {
+                let cost = costs[node] + weight;
+
+                if cost < costs[next] {
+                    costs[next] = cost;
+                    let heuristic = self.crow_router.route(self.nodes[next], self.nodes[goal]);
+
+                    open_set.push(Reverse((
+                        OrderedFloat(cost + self.greedy_weight * heuristic),
+                        next,
+                    )));
+                }
+            }
+        }
+
+        f64::INFINITY
+    }
+}
+
+impl Router for GraphRouter {
+    fn route(&self, from: Location, to: Location) -> f64 {
+        match (self.index(from), self.index(to)) {
+            (Some(start), Some(goal)) => self.search(start, goal),
+            _ => f64::INFINITY,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_along_shortest_path() {
+        let nodes = vec![
+            Location::new(0.0, 0.0),
+            Location::new(1.0, 0.0),
+            Location::new(2.0, 0.0),
+        ];
+        let edges = vec![vec![(1, 1.0)], vec![(0, 1.0), (2, 1.0)], vec![(1, 1.0)]];
+        let router = GraphRouter::new(nodes.clone(), edges, 1.0);
+
+        assert_eq!(router.route(nodes[0], nodes[2]), 2.0);
+    }
+
+    #[test]
+    fn returns_infinity_for_disconnected_goal() {
+        let nodes = vec![Location::new(0.0, 0.0), Location::new(1.0, 0.0)];
+        let edges = vec![vec![], vec![]];
+        let router = GraphRouter::new(nodes.clone(), edges, 1.0);
+
+        assert_eq!(router.route(nodes[0], nodes[1]), f64::INFINITY);
+    }
+
+    #[test]
+    fn returns_infinity_for_unknown_location() {
+        let nodes = vec![Location::new(0.0, 0.0), Location::new(1.0, 0.0)];
+        let edges = vec![vec![(1, 1.0)], vec![(0, 1.0)]];
+        let router = GraphRouter::new(nodes, edges, 1.0);
+
+        assert_eq!(
+            router.route(Location::new(0.0, 0.0), Location::new(42.0, 42.0)),
+            f64::INFINITY,
+        );
+    }
+}
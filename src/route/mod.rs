@@ -0,0 +1,30 @@
+mod cached;
+mod graph;
+
+use crate::Location;
+pub use cached::MatrixRouter;
+pub use graph::GraphRouter;
+
+pub trait Router {
+    fn route(&self, from: Location, to: Location) -> f64;
+}
+
+pub struct CrowRouter;
+
+impl CrowRouter {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Router for CrowRouter {
+    fn route(&self, from: Location, to: Location) -> f64 {
+        ((from.x() - to.x()).powi(2) + (from.y() - to.y()).powi(2)).sqrt()
+    }
+}
+
+impl<R: Router> Router for &R {
+    fn route(&self, from: Location, to: Location) -> f64 {
+        (**self).route(from, to)
+    }
+}
@@ -0,0 +1,10 @@
+mod multi_start;
+mod nearest_neighbor;
+mod ruin_and_recreate;
+mod solver;
+mod two_opt;
+
+pub use self::{
+    multi_start::MultiStartSolver, nearest_neighbor::NearestNeighborSolver,
+    ruin_and_recreate::RuinAndRecreateSolver, solver::Solver, two_opt::TwoOptSolver,
+};
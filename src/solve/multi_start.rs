@@ -0,0 +1,119 @@
+use super::solver::Solver;
+use crate::{cost::CostCalculator, problem::BaseProblem, Solution};
+use ordered_float::OrderedFloat;
+
+pub struct MultiStartSolver<C: CostCalculator, S: Solver> {
+    cost_calculator: C,
+    solvers: Vec<S>,
+}
+
+impl<C: CostCalculator, S: Solver> MultiStartSolver<C, S> {
+    pub fn new(cost_calculator: C, solvers: Vec<S>) -> Self {
+        Self {
+            cost_calculator,
+            solvers,
+        }
+    }
+
+    pub fn with_seeds(
+        cost_calculator: C,
+        start_count: u64,
+        build_solver: impl Fn(u64) -> S,
+    ) -> Self {
+        Self::new(cost_calculator, (0..start_count).map(build_solver).collect())
+    }
+}
+
+impl<C: CostCalculator + Clone, S: Solver + Clone> Solver for MultiStartSolver<C, S> {
+    fn solve(&mut self, problem: impl BaseProblem) -> Solution {
+        self.solvers
+            .iter_mut()
+            .map(|solver| {
+                let solution = solver.solve(problem);
+                let cost = self.cost_calculator.clone().calculate(&solution);
+
+                (solution, cost)
+            })
+            .min_by_key(|(_, cost)| OrderedFloat(*cost))
+            .expect("at least one solver")
+            .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cost::{DeliveryCostCalculator, DistanceCostCalculator},
+        route::CrowRouter,
+        solve::{NearestNeighborSolver, RuinAndRecreateSolver},
+        Location, SimpleProblem, Stop, Vehicle,
+    };
+
+    const DISTANCE_COST: f64 = 1.0;
+    const MISSED_DELIVERY_COST: f64 = 1e9;
+    const ITERATION_COUNT: usize = 20;
+    const INITIAL_TEMPERATURE: f64 = 10.0;
+    const COOLING_RATE: f64 = 0.995;
+
+    static ROUTER: CrowRouter = CrowRouter::new();
+
+    fn solve(problem: &SimpleProblem, start_count: u64) -> Solution {
+        MultiStartSolver::with_seeds(
+            DeliveryCostCalculator::new(
+                DistanceCostCalculator::new(&ROUTER, problem),
+                problem.stops().len(),
+                MISSED_DELIVERY_COST,
+                DISTANCE_COST,
+            ),
+            start_count,
+            |seed| {
+                RuinAndRecreateSolver::new(
+                    DeliveryCostCalculator::new(
+                        DistanceCostCalculator::new(&ROUTER, problem),
+                        problem.stops().len(),
+                        MISSED_DELIVERY_COST,
+                        DISTANCE_COST,
+                    ),
+                    &ROUTER,
+                    NearestNeighborSolver::new(&ROUTER),
+                    ITERATION_COUNT,
+                    INITIAL_TEMPERATURE,
+                    COOLING_RATE,
+                    seed,
+                )
+            },
+        )
+        .solve(problem)
+    }
+
+    #[test]
+    fn single_start() {
+        let problem = SimpleProblem::new(
+            vec![Vehicle::new()],
+            vec![
+                Stop::new(Location::new(0.0, 0.0)),
+                Stop::new(Location::new(1.0, 0.0)),
+            ],
+        );
+
+        assert_eq!(solve(&problem, 1), Solution::new(vec![vec![0, 1].into()]));
+    }
+
+    #[test]
+    fn distinct_seeds_still_cover_every_stop() {
+        let problem = SimpleProblem::new(
+            vec![Vehicle::new(), Vehicle::new()],
+            vec![
+                Stop::new(Location::new(0.0, 0.0)),
+                Stop::new(Location::new(1.0, 0.0)),
+                Stop::new(Location::new(2.0, 0.0)),
+            ],
+        );
+
+        let solution = solve(&problem, 4);
+        let stop_count = solution.routes().iter().map(|stops| stops.len()).sum::<usize>();
+
+        assert_eq!(stop_count, 3);
+    }
+}
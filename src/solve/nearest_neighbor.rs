@@ -3,6 +3,7 @@ use crate::{problem::BaseProblem, route::Router, Solution};
 use ordered_float::OrderedFloat;
 use std::collections::HashSet;
 
+#[derive(Clone)]
 pub struct NearestNeighborSolver<R: Router> {
     router: R,
 }
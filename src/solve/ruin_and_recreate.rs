@@ -1,15 +1,16 @@
 use super::solver::Solver;
 use crate::{
-    cost::CostCalculator, hash_map::HashMap, problem::BaseProblem, route::Router, trace, Solution,
+    cost::CostCalculator, dp, hash_map::HashMap, problem::BaseProblem, route::Router, trace,
+    Solution,
 };
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
-use rand::{rngs::SmallRng, seq::IteratorRandom, SeedableRng};
+use rand::{rngs::SmallRng, seq::IteratorRandom, Rng, SeedableRng};
 use std::ops::Range;
 
-const SEED: [u8; 32] = [0u8; 32];
 const MAX_VEHICLE_REGION_SIZE: usize = 2;
 const MAX_STOP_REGION_SIZE: usize = 6;
+const MAX_EXACT_REGION_STOP_COUNT: usize = 15;
 
 #[derive(Debug)]
 struct RouteRegion {
@@ -17,22 +18,35 @@ struct RouteRegion {
     stop_range: Range<usize>,
 }
 
+#[derive(Clone)]
 pub struct RuinAndRecreateSolver<C: CostCalculator, R: Router, S: Solver> {
     initial_solver: S,
     cost_calculator: C,
     router: R,
     iteration_count: usize,
+    initial_temperature: f64,
+    cooling_rate: f64,
     rng: SmallRng,
 }
 
 impl<C: CostCalculator, R: Router, S: Solver> RuinAndRecreateSolver<C, R, S> {
-    pub fn new(cost_calculator: C, router: R, initial_solver: S, iteration_count: usize) -> Self {
+    pub fn new(
+        cost_calculator: C,
+        router: R,
+        initial_solver: S,
+        iteration_count: usize,
+        initial_temperature: f64,
+        cooling_rate: f64,
+        seed: u64,
+    ) -> Self {
         Self {
             initial_solver,
             cost_calculator,
             router,
             iteration_count,
-            rng: SmallRng::from_seed(SEED),
+            initial_temperature,
+            cooling_rate,
+            rng: SmallRng::seed_from_u64(seed),
         }
     }
 
@@ -93,6 +107,7 @@ impl<C: CostCalculator, R: Router, S: Solver> RuinAndRecreateSolver<C, R, S> {
 
     fn optimize_regions(
         &mut self,
+        problem: &impl BaseProblem,
         initial_solution: &Solution,
         regions: &[RouteRegion],
     ) -> Solution {
@@ -102,6 +117,36 @@ impl<C: CostCalculator, R: Router, S: Solver> RuinAndRecreateSolver<C, R, S> {
             solution = solution.ruin_route(region.vehicle_index, region.stop_range.clone())
         }
 
+        if let [region] = regions {
+            let stop_indexes = Self::region_stop_indexes(region, initial_solution).collect_vec();
+
+            if stop_indexes.len() <= MAX_EXACT_REGION_STOP_COUNT {
+                let order = dp::solve(
+                    &self.router,
+                    problem.vehicle_start_location(region.vehicle_index),
+                    problem.vehicle_end_location(region.vehicle_index),
+                    &stop_indexes
+                        .iter()
+                        .map(|&stop_index| problem.stop_location(stop_index))
+                        .collect_vec(),
+                );
+
+                let mut exact_solution = solution.clone();
+
+                for index in order.into_iter().rev() {
+                    exact_solution = exact_solution.insert_stop(
+                        region.vehicle_index,
+                        region.stop_range.start,
+                        stop_indexes[index],
+                    );
+                }
+
+                if self.cost_calculator.calculate(&exact_solution).is_finite() {
+                    return exact_solution;
+                }
+            }
+        }
+
         let cost = self.cost_calculator.calculate(&solution);
 
         let mut solutions = HashMap::default();
@@ -185,27 +230,38 @@ impl<C: CostCalculator, R: Router, S: Solver> Solver for RuinAndRecreateSolver<C
 
         let mut solution = self.initial_solver.solve(problem);
         let mut cost = self.cost_calculator.calculate(&solution);
+        let mut best_solution = solution.clone();
+        let mut best_cost = cost;
+        let mut temperature = self.initial_temperature;
 
+        // TODO Save multiple solutions.
+        // TODO Decide if a solution is good enough already.
         for _ in 0..self.iteration_count {
             let regions = self.choose_regions(&solution, &closest_stops);
             trace!("regions: {:?}", &regions);
-            let new_solution = self.optimize_regions(&solution, &regions);
+            let new_solution = self.optimize_regions(&problem, &solution, &regions);
             let new_cost = self.cost_calculator.calculate(&new_solution);
 
-            // TODO Consider a non-greedy strategy like simulated annealing.
-            // TODO Save multiple solutions.
-            // TODO Decide if a solution is good enough already.
-            if new_cost < cost {
+            if new_cost < cost
+                || self.rng.gen::<f64>() < (-(new_cost - cost) / temperature).exp()
+            {
                 trace!("new solution found!");
                 trace!("solution: {:?}", solution);
                 trace!("cost: {:?}", cost);
 
                 solution = new_solution;
                 cost = new_cost;
+
+                if cost < best_cost {
+                    best_solution = solution.clone();
+                    best_cost = cost;
+                }
             }
+
+            temperature *= self.cooling_rate;
         }
 
-        solution
+        best_solution
     }
 }
 
@@ -214,7 +270,7 @@ mod tests {
     use super::*;
     use crate::{
         cost::{DeliveryCostCalculator, DistanceCostCalculator},
-        route::CrowRouter,
+        route::{CrowRouter, MatrixRouter},
         solve::NearestNeighborSolver,
         Location, SimpleProblem, Stop, Vehicle,
     };
@@ -222,20 +278,46 @@ mod tests {
     const DISTANCE_COST: f64 = 1.0;
     const MISSED_DELIVERY_COST: f64 = 1e9;
     const ITERATION_COUNT: usize = 100;
+    const INITIAL_TEMPERATURE: f64 = 10.0;
+    const COOLING_RATE: f64 = 0.995;
+    const SEED: u64 = 0;
 
     static ROUTER: CrowRouter = CrowRouter::new();
 
+    fn total_cost(problem: &SimpleProblem, solution: &Solution) -> f64 {
+        solution
+            .routes()
+            .iter()
+            .enumerate()
+            .map(|(vehicle_index, stops)| {
+                let mut locations = vec![problem.vehicle_start_location(vehicle_index)];
+                locations.extend(stops.iter().map(|&index| problem.stop_location(index)));
+                locations.push(problem.vehicle_end_location(vehicle_index));
+
+                locations.windows(2).map(|pair| ROUTER.route(pair[0], pair[1])).sum::<f64>()
+            })
+            .sum()
+    }
+
     fn solve(problem: &SimpleProblem) -> Solution {
+        // Build the distance matrix once so that it is shared by the cost
+        // calculator, the R&R solver's closest-stop scan, and the Held-Karp
+        // reinsertion, instead of each of them re-querying `ROUTER`.
+        let matrix_router = MatrixRouter::new(&ROUTER, problem);
+
         RuinAndRecreateSolver::new(
             DeliveryCostCalculator::new(
-                DistanceCostCalculator::new(&ROUTER, problem),
+                DistanceCostCalculator::new(&matrix_router, problem),
                 problem.stops().len(),
                 MISSED_DELIVERY_COST,
                 DISTANCE_COST,
             ),
-            &ROUTER,
+            &matrix_router,
             NearestNeighborSolver::new(&ROUTER),
             ITERATION_COUNT,
+            INITIAL_TEMPERATURE,
+            COOLING_RATE,
+            SEED,
         )
         .solve(problem)
     }
@@ -284,6 +366,69 @@ mod tests {
         assert_eq!(solve(&problem), Solution::new(vec![vec![0, 1, 2].into()]));
     }
 
+    fn crossing_problem() -> SimpleProblem {
+        SimpleProblem::new(
+            vec![Vehicle::new(
+                Location::new(0.0, 0.0),
+                Location::new(0.0, 0.0),
+            )],
+            vec![
+                Stop::new(Location::new(1.0, 0.0)),
+                Stop::new(Location::new(3.0, 2.0)),
+                Stop::new(Location::new(3.0, 3.0)),
+                Stop::new(Location::new(1.0, 2.0)),
+            ],
+        )
+    }
+
+    #[test]
+    fn annealing_improves_on_the_initial_solution() {
+        let problem = crossing_problem();
+        let matrix_router = MatrixRouter::new(&ROUTER, &problem);
+
+        let initial = RuinAndRecreateSolver::new(
+            DeliveryCostCalculator::new(
+                DistanceCostCalculator::new(&matrix_router, &problem),
+                problem.stops().len(),
+                MISSED_DELIVERY_COST,
+                DISTANCE_COST,
+            ),
+            &matrix_router,
+            NearestNeighborSolver::new(&ROUTER),
+            0,
+            INITIAL_TEMPERATURE,
+            COOLING_RATE,
+            SEED,
+        )
+        .solve(&problem);
+
+        assert!(total_cost(&problem, &solve(&problem)) < total_cost(&problem, &initial));
+    }
+
+    #[test]
+    fn best_solution_is_never_regressed_by_an_accepted_worse_move() {
+        let problem = crossing_problem();
+        let matrix_router = MatrixRouter::new(&ROUTER, &problem);
+
+        let zero_iterations = RuinAndRecreateSolver::new(
+            DeliveryCostCalculator::new(
+                DistanceCostCalculator::new(&matrix_router, &problem),
+                problem.stops().len(),
+                MISSED_DELIVERY_COST,
+                DISTANCE_COST,
+            ),
+            &matrix_router,
+            NearestNeighborSolver::new(&ROUTER),
+            0,
+            INITIAL_TEMPERATURE,
+            COOLING_RATE,
+            SEED,
+        )
+        .solve(&problem);
+
+        assert!(total_cost(&problem, &solve(&problem)) <= total_cost(&problem, &zero_iterations));
+    }
+
     #[test]
     fn even_workload() {
         let problem = SimpleProblem::new(
@@ -0,0 +1,184 @@
+use super::solver::Solver;
+use crate::{problem::BaseProblem, route::Router, Location, Solution};
+
+#[derive(Clone)]
+pub struct TwoOptSolver<R: Router, S: Solver> {
+    router: R,
+    initial_solver: S,
+}
+
+impl<R: Router, S: Solver> TwoOptSolver<R, S> {
+    pub fn new(router: R, initial_solver: S) -> Self {
+        Self {
+            router,
+            initial_solver,
+        }
+    }
+
+    fn location_before(
+        problem: &impl BaseProblem,
+        vehicle_index: usize,
+        stops: &[usize],
+        index: usize,
+    ) -> Location {
+        if index == 0 {
+            problem.vehicle_start_location(vehicle_index)
+        } else {
+            problem.stop_location(stops[index - 1])
+        }
+    }
+
+    fn location_after(
+        problem: &impl BaseProblem,
+        vehicle_index: usize,
+        stops: &[usize],
+        index: usize,
+    ) -> Location {
+        if index == stops.len() {
+            problem.vehicle_end_location(vehicle_index)
+        } else {
+            problem.stop_location(stops[index])
+        }
+    }
+
+    fn edge_cost(
+        &self,
+        problem: &impl BaseProblem,
+        vehicle_index: usize,
+        stops: &[usize],
+        index: usize,
+    ) -> f64 {
+        self.router.route(
+            Self::location_before(problem, vehicle_index, stops, index),
+            Self::location_after(problem, vehicle_index, stops, index),
+        )
+    }
+
+    fn reversal_delta(
+        &self,
+        problem: &impl BaseProblem,
+        vehicle_index: usize,
+        stops: &[usize],
+        i: usize,
+        j: usize,
+    ) -> f64 {
+        let old_cost =
+            self.edge_cost(problem, vehicle_index, stops, i)
+                + self.edge_cost(problem, vehicle_index, stops, j + 1);
+        let new_cost = self.router.route(
+            Self::location_before(problem, vehicle_index, stops, i),
+            problem.stop_location(stops[j]),
+        ) + self.router.route(
+            problem.stop_location(stops[i]),
+            Self::location_after(problem, vehicle_index, stops, j + 1),
+        );
+
+        new_cost - old_cost
+    }
+
+    fn optimize_route(
+        &self,
+        problem: &impl BaseProblem,
+        vehicle_index: usize,
+        stops: &mut [usize],
+    ) {
+        loop {
+            let mut improved = false;
+
+            for i in 0..stops.len() {
+                for j in (i + 1)..stops.len() {
+                    if self.reversal_delta(problem, vehicle_index, stops, i, j) < 0.0 {
+                        stops[i..=j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+
+            if !improved {
+                break;
+            }
+        }
+    }
+}
+
+impl<R: Router, S: Solver> Solver for TwoOptSolver<R, S> {
+    fn solve(&mut self, problem: impl BaseProblem) -> Solution {
+        let solution = self.initial_solver.solve(problem);
+        let mut routes = solution
+            .routes()
+            .iter()
+            .map(|route| route.to_vec())
+            .collect::<Vec<_>>();
+
+        for (vehicle_index, stops) in routes.iter_mut().enumerate() {
+            self.optimize_route(&problem, vehicle_index, stops);
+        }
+
+        Solution::new(routes.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{route::CrowRouter, solve::NearestNeighborSolver, SimpleProblem, Stop, Vehicle};
+
+    static ROUTER: CrowRouter = CrowRouter::new();
+
+    fn solve(problem: &SimpleProblem) -> Solution {
+        TwoOptSolver::new(&ROUTER, NearestNeighborSolver::new(&ROUTER)).solve(problem)
+    }
+
+    #[test]
+    fn do_nothing() {
+        let problem = SimpleProblem::new(vec![Vehicle::new()], vec![]);
+
+        assert_eq!(solve(&problem), Solution::new(vec![vec![].into()]));
+    }
+
+    #[test]
+    fn keep_already_optimal_order() {
+        let problem = SimpleProblem::new(
+            vec![Vehicle::new()],
+            vec![
+                Stop::new(Location::new(0.0, 0.0)),
+                Stop::new(Location::new(1.0, 0.0)),
+                Stop::new(Location::new(2.0, 0.0)),
+            ],
+        );
+
+        assert_eq!(solve(&problem), Solution::new(vec![vec![0, 1, 2].into()]));
+    }
+
+    fn route_cost(problem: &SimpleProblem, stops: &[usize]) -> f64 {
+        let mut locations = vec![problem.vehicle_start_location(0)];
+        locations.extend(stops.iter().map(|&index| problem.stop_location(index)));
+        locations.push(problem.vehicle_end_location(0));
+
+        locations.windows(2).map(|pair| ROUTER.route(pair[0], pair[1])).sum()
+    }
+
+    #[test]
+    fn improves_crossing_route() {
+        let problem = SimpleProblem::new(
+            vec![Vehicle::new(
+                Location::new(0.0, 0.0),
+                Location::new(0.0, 0.0),
+            )],
+            vec![
+                Stop::new(Location::new(1.0, 0.0)),
+                Stop::new(Location::new(3.0, 2.0)),
+                Stop::new(Location::new(3.0, 3.0)),
+                Stop::new(Location::new(1.0, 2.0)),
+            ],
+        );
+
+        let nn_only = NearestNeighborSolver::new(&ROUTER).solve(&problem);
+        let polished = solve(&problem);
+
+        assert!(
+            route_cost(&problem, &polished.routes()[0])
+                < route_cost(&problem, &nn_only.routes()[0])
+        );
+    }
+}